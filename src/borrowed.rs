@@ -0,0 +1,291 @@
+//! Borrowed, copy-free parsing of a [`BinaryCurveCollection`] over an in-memory buffer.
+
+use std::borrow::Cow;
+use std::io::{Error, ErrorKind};
+
+use crate::{BinaryCurveCollection, BinaryCurveCollectionHeader, BinaryCurveCollectionParserError};
+
+/// Byte offset at which the per-curve data begins, after the fixed-size header.
+const HEADER_SIZE: usize = 64;
+
+/// A [`BinaryCurveCollection`] parsed in place over a borrowed byte buffer.
+///
+/// The header is validated on construction and each curve's control points are exposed
+/// directly over the backing bytes, copying only when the buffer is misaligned or the host
+/// is big-endian. This gives a copy-free fast path for bulk loading, e.g. over a memory
+/// mapped `.bcc` file. Convert to the fully owned [`BinaryCurveCollection`] with
+/// [`to_owned`](BinaryCurveCollectionRef::to_owned).
+pub struct BinaryCurveCollectionRef<'a> {
+    /// Backing byte buffer.
+    data: &'a [u8],
+    /// Header parsed from the buffer.
+    header: BinaryCurveCollectionHeader,
+    /// Per-curve records describing where each curve's control points live.
+    curves: Box<[CurveRef]>,
+}
+
+/// Location of a single curve's control points within the backing buffer.
+struct CurveRef {
+    /// Whether the curve is looping.
+    looping: bool,
+    /// Byte offset of the curve's first control-point component.
+    offset: usize,
+    /// Number of control points in the curve.
+    point_count: usize,
+}
+
+impl<'a> BinaryCurveCollectionRef<'a> {
+    /// Validates `data` in place and returns a borrowed view over it.
+    pub fn parse_borrowed(data: &'a [u8]) -> Result<Self, BinaryCurveCollectionParserError> {
+        let header_bytes = data
+            .get(..HEADER_SIZE)
+            .ok_or_else(|| Error::from(ErrorKind::UnexpectedEof))?;
+
+        if header_bytes[0..3] != [b'B', b'C', b'C'] {
+            return Err(BinaryCurveCollectionParserError::InvalidSignature);
+        }
+        let precision = header_bytes[3];
+        let component_width = match precision {
+            0x44 => 4u8,
+            0x48 => 8,
+            _ => return Err(BinaryCurveCollectionParserError::InvalidPrecision),
+        };
+        let curve = [header_bytes[4], header_bytes[5]];
+        if curve != [b'C', b'0'] {
+            return Err(BinaryCurveCollectionParserError::InvalidCurve);
+        }
+        let dimensions = header_bytes[6];
+        if !(2..=3).contains(&dimensions) {
+            return Err(BinaryCurveCollectionParserError::InvalidDimensions);
+        }
+        let up_direction = header_bytes[7];
+        if !(1..=2).contains(&up_direction) {
+            return Err(BinaryCurveCollectionParserError::InvalidUpDirection);
+        }
+        let number_of_curves = u64::from_le_bytes(header_bytes[8..16].try_into().unwrap());
+        let number_of_control_points = u64::from_le_bytes(header_bytes[16..24].try_into().unwrap());
+        let mut file_information = [0; 40];
+        file_information.copy_from_slice(&header_bytes[24..64]);
+
+        let Ok(size_of_curves) = usize::try_from(number_of_curves) else {
+            return Err(BinaryCurveCollectionParserError::TooManyCurves);
+        };
+
+        let dimensions_size = usize::from(dimensions);
+        let width = usize::from(component_width);
+
+        let mut curves = Vec::with_capacity(size_of_curves);
+        let mut cursor = HEADER_SIZE;
+        for _ in 0..size_of_curves {
+            let count_bytes = data
+                .get(cursor..cursor + 4)
+                .ok_or_else(|| Error::from(ErrorKind::UnexpectedEof))?;
+            let count = i32::from_le_bytes(count_bytes.try_into().unwrap());
+            cursor += 4;
+
+            let Ok(point_count) = usize::try_from(count.unsigned_abs()) else {
+                return Err(BinaryCurveCollectionParserError::TooManyControlPoints);
+            };
+            let span = point_count * dimensions_size * width;
+            if data.len() < cursor + span {
+                return Err(Error::from(ErrorKind::UnexpectedEof).into());
+            }
+
+            curves.push(CurveRef {
+                looping: count < 0,
+                offset: cursor,
+                point_count,
+            });
+            cursor += span;
+        }
+
+        let header = BinaryCurveCollectionHeader {
+            signature: [b'B', b'C', b'C'],
+            precision,
+            component_width,
+            curve,
+            dimensions,
+            up_direction,
+            number_of_curves,
+            number_of_control_points,
+            file_information,
+        };
+
+        Ok(Self {
+            data,
+            header,
+            curves: curves.into_boxed_slice(),
+        })
+    }
+
+    /// Get the header of the collection.
+    pub fn header(&self) -> &BinaryCurveCollectionHeader {
+        &self.header
+    }
+
+    /// Get the number of curves in the collection.
+    pub fn number_of_curves(&self) -> usize {
+        self.curves.len()
+    }
+
+    /// Check if the Nth curve is looping.
+    pub fn looping(&self, n: usize) -> Option<bool> {
+        self.curves.get(n).map(|curve| curve.looping)
+    }
+
+    /// Get the flattened control points of the Nth curve.
+    ///
+    /// Returns a [`Cow::Borrowed`] pointing straight into the backing buffer on the common
+    /// little-endian, single-precision, correctly-aligned fast path, and a [`Cow::Owned`]
+    /// copy only when the buffer is misaligned, big-endian, or double precision.
+    pub fn control_points(&self, n: usize) -> Option<Cow<'a, [f32]>> {
+        let curve = self.curves.get(n)?;
+        let dimensions = usize::from(self.header.dimensions);
+        let width = usize::from(self.header.component_width);
+        let components = curve.point_count * dimensions;
+        let bytes = &self.data[curve.offset..(curve.offset + components * width)];
+
+        if width == 4 && cfg!(target_endian = "little") {
+            // SAFETY: `f32` has no invalid bit patterns, so any aligned run of 4 bytes is a
+            // valid `f32`; `align_to` keeps us inside `bytes`.
+            let (prefix, floats, suffix) = unsafe { bytes.align_to::<f32>() };
+            if prefix.is_empty() && suffix.is_empty() {
+                return Some(Cow::Borrowed(floats));
+            }
+            return Some(Cow::Owned(
+                bytes
+                    .chunks_exact(4)
+                    .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+                    .collect(),
+            ));
+        }
+
+        if width == 8 {
+            return Some(Cow::Owned(
+                bytes
+                    .chunks_exact(8)
+                    .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()) as f32)
+                    .collect(),
+            ));
+        }
+
+        Some(Cow::Owned(
+            bytes
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+                .collect(),
+        ))
+    }
+
+    /// Convert this borrowed view into a fully owned [`BinaryCurveCollection`].
+    pub fn to_owned(&self) -> BinaryCurveCollection {
+        let mut looping = Vec::with_capacity(self.curves.len());
+        let mut first_control_points = Vec::with_capacity(self.curves.len() + 1);
+        let mut control_points = Vec::new();
+
+        let mut start = 0;
+        for (n, curve) in self.curves.iter().enumerate() {
+            looping.push(curve.looping);
+            first_control_points.push(start);
+            if let Some(points) = self.control_points(n) {
+                control_points.extend_from_slice(&points);
+            }
+            start += curve.point_count;
+        }
+        first_control_points.push(start);
+
+        BinaryCurveCollection {
+            header: self.header,
+            looping: looping.into_boxed_slice(),
+            first_control_points: first_control_points.into_boxed_slice(),
+            control_points: control_points.into_boxed_slice(),
+            #[cfg(feature = "bevy")]
+            render_asset_usages: bevy_asset::RenderAssetUsages::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::BinaryCurveCollectionRef;
+
+    /// Assemble a `.bcc` byte buffer; `precision` selects single (`0x44`) or double (`0x48`).
+    fn bcc_bytes(precision: u8, dimensions: u8, up: u8, curves: &[(bool, &[f64])]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"BCC");
+        bytes.push(precision);
+        bytes.extend_from_slice(b"C0");
+        bytes.push(dimensions);
+        bytes.push(up);
+        bytes.extend_from_slice(&(curves.len() as u64).to_le_bytes());
+        let total: usize = curves
+            .iter()
+            .map(|(_, points)| points.len() / dimensions as usize)
+            .sum();
+        bytes.extend_from_slice(&(total as u64).to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 40]);
+        for (looping, points) in curves {
+            let count = (points.len() / dimensions as usize) as i32;
+            let count = if *looping { -count } else { count };
+            bytes.extend_from_slice(&count.to_le_bytes());
+            for value in *points {
+                if precision == 0x48 {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                } else {
+                    bytes.extend_from_slice(&(*value as f32).to_le_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn single_precision_reads_control_points() {
+        let bytes = bcc_bytes(0x44, 3, 1, &[(false, &[0.0, 1.5, 2.5])]);
+        let parsed = BinaryCurveCollectionRef::parse_borrowed(&bytes).unwrap();
+        assert_eq!(parsed.number_of_curves(), 1);
+        assert_eq!(parsed.looping(0), Some(false));
+        assert_eq!(parsed.control_points(0).unwrap().to_vec(), vec![0.0_f32, 1.5, 2.5]);
+    }
+
+    #[test]
+    fn double_precision_copies_through_owned() {
+        let bytes = bcc_bytes(0x48, 3, 1, &[(true, &[0.0, 1.5, 2.5])]);
+        let parsed = BinaryCurveCollectionRef::parse_borrowed(&bytes).unwrap();
+        assert_eq!(parsed.header().component_width(), 8);
+        assert_eq!(parsed.looping(0), Some(true));
+        let points = parsed.control_points(0).unwrap();
+        // Double precision always needs the owned, down-converting copy path.
+        assert!(matches!(points, Cow::Owned(_)));
+        assert_eq!(points.to_vec(), vec![0.0_f32, 1.5, 2.5]);
+    }
+
+    #[test]
+    fn to_owned_round_trips_multiple_curves() {
+        let bytes = bcc_bytes(
+            0x44,
+            2,
+            2,
+            &[(false, &[0.0, 1.0]), (true, &[2.0, 3.0, 4.0, 5.0])],
+        );
+        let owned = BinaryCurveCollectionRef::parse_borrowed(&bytes).unwrap().to_owned();
+        assert_eq!(owned.header().number_of_curves(), 2);
+        assert_eq!(owned.looping(1), Some(true));
+        assert_eq!(
+            owned.control_points().to_vec(),
+            vec![0.0_f32, 1.0, 2.0, 3.0, 4.0, 5.0]
+        );
+        assert_eq!(owned.first_control_point(0), Some(0));
+        assert_eq!(owned.first_control_point(1), Some(1));
+        assert_eq!(owned.first_control_point(2), Some(3));
+    }
+
+    #[test]
+    fn rejects_truncated_buffer() {
+        let mut bytes = bcc_bytes(0x44, 3, 1, &[(false, &[0.0, 1.0, 2.0])]);
+        bytes.truncate(bytes.len() - 4);
+        assert!(BinaryCurveCollectionRef::parse_borrowed(&bytes).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! Builder for assembling a [`BinaryCurveCollection`] from in-memory control-point data.
+
+use crate::{BinaryCurveCollection, BinaryCurveCollectionHeader};
+
+/// Builder that assembles a [`BinaryCurveCollection`] from user-provided control points and
+/// loop flags, ready to be serialized with [`BinaryCurveCollection::write`].
+///
+/// This lets users procedurally generate yarn-level models instead of only consuming them.
+pub struct BinaryCurveCollectionBuilder {
+    /// Number of dimensions of each control point. Either `2` or `3`.
+    dimensions: u8,
+    /// Up direction. `1` for Y-up, `2` for Z-up.
+    up_direction: u8,
+    /// File information written to the 40-byte ASCII field, null-padded.
+    file_information: [u8; 40],
+    /// Curves accumulated so far, as `(looping, flattened control points)`.
+    curves: Vec<(bool, Vec<f32>)>,
+}
+
+impl BinaryCurveCollectionBuilder {
+    /// Create a builder for curves of the given dimension count and up direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is not `2` or `3`, or if `up_direction` is not `1` (Y-up) or
+    /// `2` (Z-up), so the invariant the rest of the crate relies on holds for
+    /// builder-constructed collections just as it does for parsed ones.
+    pub fn new(dimensions: u8, up_direction: u8) -> Self {
+        assert!(
+            (2..=3).contains(&dimensions),
+            "BCC files only support 2 or 3 dimensional curves, got {dimensions}."
+        );
+        assert!(
+            (1..=2).contains(&up_direction),
+            "BCC files only support `1` Y-up or `2` Z-up coordinate systems, got {up_direction}."
+        );
+        Self {
+            dimensions,
+            up_direction,
+            file_information: [0; 40],
+            curves: Vec::new(),
+        }
+    }
+
+    /// Set the ASCII file-information field, truncated or null-padded to 40 bytes.
+    pub fn with_file_information(mut self, file_information: &str) -> Self {
+        let bytes = file_information.as_bytes();
+        let len = bytes.len().min(self.file_information.len());
+        self.file_information = [0; 40];
+        self.file_information[..len].copy_from_slice(&bytes[..len]);
+        self
+    }
+
+    /// Append a curve from its flattened control points. A looping curve is encoded by the
+    /// sign of its control-point count when written.
+    pub fn add_curve(mut self, looping: bool, control_points: impl Into<Vec<f32>>) -> Self {
+        self.curves.push((looping, control_points.into()));
+        self
+    }
+
+    /// Assemble the accumulated curves into a [`BinaryCurveCollection`].
+    pub fn build(self) -> BinaryCurveCollection {
+        let dimensions = usize::from(self.dimensions);
+
+        let mut looping = Vec::with_capacity(self.curves.len());
+        let mut first_control_points = Vec::with_capacity(self.curves.len() + 1);
+        let mut control_points = Vec::new();
+
+        let mut start = 0;
+        for (is_looping, points) in self.curves {
+            debug_assert_eq!(
+                points.len() % dimensions,
+                0,
+                "Curve control points must be a multiple of the dimension count."
+            );
+            looping.push(is_looping);
+            first_control_points.push(start);
+            start += points.len() / dimensions;
+            control_points.extend_from_slice(&points);
+        }
+        first_control_points.push(start);
+
+        let header = BinaryCurveCollectionHeader {
+            signature: [b'B', b'C', b'C'],
+            precision: 0x44,
+            component_width: 4,
+            curve: [b'C', b'0'],
+            dimensions: self.dimensions,
+            up_direction: self.up_direction,
+            number_of_curves: looping.len() as u64,
+            number_of_control_points: start as u64,
+            file_information: self.file_information,
+        };
+
+        BinaryCurveCollection {
+            header,
+            looping: looping.into_boxed_slice(),
+            first_control_points: first_control_points.into_boxed_slice(),
+            control_points: control_points.into_boxed_slice(),
+            #[cfg(feature = "bevy")]
+            render_asset_usages: bevy_asset::RenderAssetUsages::default(),
+        }
+    }
+}
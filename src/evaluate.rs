@@ -0,0 +1,210 @@
+//! Evaluation and tessellation of the Catmull-Rom curves in a [`BinaryCurveCollection`].
+
+use crate::BinaryCurveCollection;
+
+impl BinaryCurveCollection {
+    /// Sample curve `n` at parameter `t`, with `t` spanning the whole curve over `[0, 1]`.
+    ///
+    /// The header guarantees uniform-parameterization Catmull-Rom curves, so for a segment
+    /// between `P1` and `P2` (with neighbors `P0`, `P3`) and local parameter `t`
+    ///
+    /// `P(t) = 0.5 * (2*P1 + (-P0 + P2)*t + (2*P0 - 5*P1 + 4*P2 - P3)*t² + (-P0 + 3*P1 - 3*P2 + P3)*t³)`
+    ///
+    /// is applied component-wise over the [`dimensions`](crate::BinaryCurveCollectionHeader::dimensions)
+    /// channels. Looping curves wrap their neighbor indices so the spline closes; open curves
+    /// synthesize phantom endpoints by reflection (`P₋₁ = 2*P₀ − P₁`, `P_last+1 = 2*P_last − P_last−1`).
+    ///
+    /// Components beyond the curve's dimension count are left at zero. Returns [`None`] for an
+    /// out-of-range `n` or `t`.
+    ///
+    /// Note that this reflects the phantom endpoints, whereas
+    /// [`resample`](Self::resample) clamps them by duplicating the first/last control point and
+    /// uses a centripetal parameterization; the two therefore bow slightly differently near
+    /// open-curve endpoints. This API follows the uniform Catmull-Rom parameterization the
+    /// header guarantees and is the one to use when you want to sample the curve as authored.
+    pub fn sample_curve(&self, n: usize, t: f32) -> Option<[f32; 3]> {
+        if !(0. ..=1.).contains(&t) {
+            return None;
+        }
+
+        let l = self.first_control_point(n)?;
+        let r = self.first_control_point(n + 1)?;
+        let looping = self.looping(n)?;
+        let count = r - l;
+        if count == 0 {
+            return None;
+        }
+
+        let dimensions = usize::from(self.header.dimensions);
+        let point = |index: isize| -> [f32; 3] {
+            self.neighbor(l, count, looping, dimensions, index)
+        };
+
+        let segments = self.segment_count(count, looping);
+        if segments == 0 {
+            // A single-point curve samples to that point regardless of `t`.
+            return Some(point(0));
+        }
+
+        let scaled = t * segments as f32;
+        let mut segment = scaled.floor() as usize;
+        let mut local = scaled - segment as f32;
+        if segment >= segments {
+            segment = segments - 1;
+            local = 1.;
+        }
+        let segment = segment as isize;
+
+        let p0 = point(segment - 1);
+        let p1 = point(segment);
+        let p2 = point(segment + 1);
+        let p3 = point(segment + 2);
+
+        let t2 = local * local;
+        let t3 = t2 * local;
+        let mut out = [0.; 3];
+        for (c, out) in out.iter_mut().enumerate().take(dimensions.min(3)) {
+            *out = 0.5
+                * (2. * p1[c]
+                    + (-p0[c] + p2[c]) * local
+                    + (2. * p0[c] - 5. * p1[c] + 4. * p2[c] - p3[c]) * t2
+                    + (-p0[c] + 3. * p1[c] - 3. * p2[c] + p3[c]) * t3);
+        }
+        Some(out)
+    }
+
+    /// Tessellate curve `n` into a polyline, sampling each segment `subdivisions` times.
+    ///
+    /// Returns the sampled points in order, closing the loop for looping curves. Returns an
+    /// empty [`Vec`] for an out-of-range `n`. `subdivisions` is clamped to at least `1`.
+    pub fn tessellate_curve(&self, n: usize, subdivisions: usize) -> Vec<[f32; 3]> {
+        let subdivisions = subdivisions.max(1);
+
+        let (Some(l), Some(r), Some(looping)) = (
+            self.first_control_point(n),
+            self.first_control_point(n + 1),
+            self.looping(n),
+        ) else {
+            return Vec::new();
+        };
+        let count = r - l;
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let segments = self.segment_count(count, looping);
+        if segments == 0 {
+            let dimensions = usize::from(self.header.dimensions);
+            return vec![self.neighbor(l, count, looping, dimensions, 0)];
+        }
+
+        let total = segments * subdivisions;
+        // Looping curves omit the duplicated closing sample.
+        let samples = if looping { total } else { total + 1 };
+        (0..samples)
+            .filter_map(|i| self.sample_curve(n, i as f32 / total as f32))
+            .collect()
+    }
+
+    /// Number of segments spanned by a curve of `count` control points.
+    fn segment_count(&self, count: usize, looping: bool) -> usize {
+        if looping {
+            count
+        } else {
+            count.saturating_sub(1)
+        }
+    }
+
+    /// Fetch the control point at `index` within a curve, wrapping for looping curves and
+    /// reflecting phantom endpoints for open curves.
+    fn neighbor(
+        &self,
+        first: usize,
+        count: usize,
+        looping: bool,
+        dimensions: usize,
+        index: isize,
+    ) -> [f32; 3] {
+        let real = |i: usize| -> [f32; 3] {
+            let base = (first + i) * dimensions;
+            let mut point = [0.; 3];
+            for (c, point) in point.iter_mut().enumerate().take(dimensions.min(3)) {
+                *point = self.control_points[base + c];
+            }
+            point
+        };
+
+        if looping {
+            return real(index.rem_euclid(count as isize) as usize);
+        }
+
+        let last = count - 1;
+        if index < 0 {
+            // Reflect across the first control point.
+            let p0 = real(0);
+            let p1 = real(1.min(last));
+            std::array::from_fn(|c| 2. * p0[c] - p1[c])
+        } else if index as usize > last {
+            // Reflect across the last control point.
+            let pn = real(last);
+            let pm = real(last.saturating_sub(1));
+            std::array::from_fn(|c| 2. * pn[c] - pm[c])
+        } else {
+            real(index as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::BinaryCurveCollectionBuilder;
+
+    #[test]
+    fn open_curve_hits_endpoints_and_midpoint() {
+        let bcc = BinaryCurveCollectionBuilder::new(3, 1)
+            .add_curve(false, vec![0., 0., 0., 2., 0., 0.])
+            .build();
+
+        // The parameter endpoints land on the control points themselves.
+        assert_eq!(bcc.sample_curve(0, 0.).unwrap(), [0., 0., 0.]);
+        assert_eq!(bcc.sample_curve(0, 1.).unwrap(), [2., 0., 0.]);
+
+        // Reflecting the phantom endpoints makes a two-point segment sample to the midpoint.
+        let mid = bcc.sample_curve(0, 0.5).unwrap();
+        assert!((mid[0] - 1.).abs() < 1e-6);
+        assert!(mid[1].abs() < 1e-6 && mid[2].abs() < 1e-6);
+    }
+
+    #[test]
+    fn tessellate_open_curve_closes_with_final_sample() {
+        let bcc = BinaryCurveCollectionBuilder::new(3, 1)
+            .add_curve(false, vec![0., 0., 0., 2., 0., 0.])
+            .build();
+        let samples = bcc.tessellate_curve(0, 2);
+        // One segment times two subdivisions, plus the closing sample for an open curve.
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0], [0., 0., 0.]);
+        assert_eq!(samples[2], [2., 0., 0.]);
+    }
+
+    #[test]
+    fn looping_curve_wraps() {
+        let bcc = BinaryCurveCollectionBuilder::new(3, 1)
+            .add_curve(true, vec![0., 0., 0., 1., 0., 0., 1., 1., 0., 0., 1., 0.])
+            .build();
+        assert_eq!(bcc.looping(0), Some(true));
+        // A looping curve starts on its first control point and omits the duplicated close.
+        assert_eq!(bcc.sample_curve(0, 0.).unwrap(), [0., 0., 0.]);
+        assert_eq!(bcc.tessellate_curve(0, 1).len(), 4);
+    }
+
+    #[test]
+    fn out_of_range_returns_none() {
+        let bcc = BinaryCurveCollectionBuilder::new(3, 1)
+            .add_curve(false, vec![0., 0., 0., 1., 0., 0.])
+            .build();
+        assert!(bcc.sample_curve(1, 0.5).is_none());
+        assert!(bcc.sample_curve(0, 1.5).is_none());
+        assert!(bcc.tessellate_curve(9, 4).is_empty());
+    }
+}
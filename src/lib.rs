@@ -2,9 +2,16 @@
 //!
 //! Created by [Cem Yuksel](https://www.cemyuksel.com/research/yarnmodels/) for defining yarn-level cloth models.
 
+pub mod borrowed;
+pub mod builder;
+pub mod evaluate;
+#[cfg(feature = "bevy")]
+pub mod mesh;
 #[cfg(feature = "bevy")]
 pub mod plugin;
 pub mod reader;
+pub mod resample;
+pub mod writer;
 
 use std::{
     error::Error,
@@ -30,6 +37,12 @@ pub struct BinaryCurveCollection {
     first_control_points: Box<[usize]>,
     /// Control points
     control_points: Box<[f32]>,
+    /// Render asset usages that meshes built from this collection inherit by default.
+    ///
+    /// Set from [`BinaryCurveCollectionLoaderSettings`](crate::plugin::BinaryCurveCollectionLoaderSettings)
+    /// when loaded through the asset loader, defaulting to [`RenderAssetUsages::default`].
+    #[cfg(feature = "bevy")]
+    render_asset_usages: RenderAssetUsages,
 }
 
 impl BinaryCurveCollection {
@@ -80,6 +93,12 @@ impl BinaryCurveCollection {
     pub fn control_points(&self) -> &[f32] {
         &self.control_points
     }
+
+    /// Get the [`RenderAssetUsages`] that meshes built from this collection inherit by default.
+    #[cfg(feature = "bevy")]
+    pub fn render_asset_usages(&self) -> RenderAssetUsages {
+        self.render_asset_usages
+    }
 }
 
 /// Header of a [`BinaryCurveCollection`]
@@ -88,13 +107,17 @@ pub struct BinaryCurveCollectionHeader {
     /// Signature of the file. Must be `BCC`.
     signature: [u8; 3],
     /// Precision of the curves. High nible represent integer precision, and must be 4.
-    /// Low nible represents float precision, and must be 4.
+    /// Low nible represents float precision, and must be 4 (single) or 8 (double).
     precision: u8,
+    /// Source byte width of a single control-point component, taken from the low nible of
+    /// [`precision`](Self::precision). Either `4` ([`f32`]) or `8` ([`f64`]); double
+    /// precision components are promoted to [`f32`] on load.
+    component_width: u8,
     /// Type of curve
     ///
     /// * `C0`: Catmull-Rom curves with uniform parameterization
     curve: [u8; 2],
-    /// Number of dimensions. Should always 3.
+    /// Number of dimensions. Either `2` or `3`.
     dimensions: u8,
     /// Up direction
     ///
@@ -112,15 +135,31 @@ pub struct BinaryCurveCollectionHeader {
 impl BinaryCurveCollectionHeader {
     /// Get the dimensions of the curves.
     ///
-    /// Should alwasy be 3.
+    /// Either `2` or `3`.
     pub fn dimensions(&self) -> u8 {
         self.dimensions
     }
 
+    /// Get the source byte width of a single control-point component.
+    ///
+    /// Either `4` ([`f32`]) or `8` ([`f64`]); note that double-precision components are
+    /// promoted to [`f32`] when stored in the [`BinaryCurveCollection`].
+    pub fn component_width(&self) -> u8 {
+        self.component_width
+    }
+
     /// Get the dimensions of the curves.
     pub fn number_of_curves(&self) -> u64 {
         self.number_of_curves
     }
+
+    /// Get the up direction of the curves.
+    ///
+    /// * `1`: Y
+    /// * `2`: Z
+    pub fn up_direction(&self) -> u8 {
+        self.up_direction
+    }
 }
 
 impl Debug for BinaryCurveCollectionHeader {
@@ -128,6 +167,7 @@ impl Debug for BinaryCurveCollectionHeader {
         f.debug_struct("BinaryCurveCollectionHeader")
             .field("signature", &String::from_utf8_lossy(&self.signature))
             .field("precision", &format!("{:#02x}", self.precision))
+            .field("component_width", &self.component_width)
             .field("curve", &String::from_utf8_lossy(&self.curve))
             .field("dimensions", &self.dimensions)
             .field(
@@ -155,8 +195,10 @@ impl Debug for BinaryCurveCollectionHeader {
 pub enum BinaryCurveCollectionParserError {
     /// The signature was not `BCC`
     InvalidSignature,
-    /// The precision was not 0x44
+    /// The precision was not one of `0x44` (single) or `0x48` (double)
     InvalidPrecision,
+    /// The number of dimensions was not `2` or `3`
+    InvalidDimensions,
     /// The curve type was not one of:
     ///
     /// * `C0`: Catmull-Rom curve with uniform parameterization
@@ -180,8 +222,11 @@ impl Display for BinaryCurveCollectionParserError {
             Self::InvalidSignature => write!(f, "BCC file had invalid signature."),
             Self::InvalidPrecision => write!(
                 f,
-                "BCC files only support 4 byte integers and 4 byte floats."
+                "BCC files only support 4 byte integers and 4 or 8 byte floats."
             ),
+            Self::InvalidDimensions => {
+                write!(f, "BCC files only support 2 or 3 dimensional curves.")
+            }
             Self::InvalidCurve => write!(
                 f,
                 "BCC files only support `C0` Catmull-Rom curves with uniform parameterization."
@@ -1,10 +1,24 @@
 //! Convertion between a [`BinaryCurveCollection`] into a Bevy [`Mesh`]
 
 use bevy_asset::RenderAssetUsages;
+use bevy_math::{Quat, Vec3};
 use bevy_mesh::{Mesh, MeshBuilder, Meshable, PrimitiveTopology, VertexAttributeValues};
 
 use crate::BinaryCurveCollection;
 
+/// Line topology emitted by [`BinaryCurveCollectionMeshBuilder`].
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinaryCurveCollectionMeshTopology {
+    /// [`PrimitiveTopology::LineStrip`], using `u32::MAX` primitive-restart indices
+    /// between curves. Compact, but primitive restart is not reliably available on the
+    /// WebGL2 backend.
+    #[default]
+    LineStrip,
+    /// [`PrimitiveTopology::LineList`], emitting an explicit index pair per segment so no
+    /// restart index is needed. Portable across WebGL2 and WebGPU.
+    LineList,
+}
+
 /// Mesh builder for a [`BinaryCurveCollection`]
 pub struct BinaryCurveCollectionMeshBuilder<'a> {
     /// The [`BinaryCurveCollection`] from which the mesh will be built from
@@ -12,6 +26,8 @@ pub struct BinaryCurveCollectionMeshBuilder<'a> {
     /// Render asset usage. Assets with [`RenderAssetUsages::RENDER_WORLD`] will have
     /// their data moved to the GPU and will become inaccessable from the Cpu.
     render_asset_usages: RenderAssetUsages,
+    /// Line topology used for the produced mesh.
+    topology: BinaryCurveCollectionMeshTopology,
 }
 
 impl BinaryCurveCollectionMeshBuilder<'_> {
@@ -33,36 +49,43 @@ impl BinaryCurveCollectionMeshBuilder<'_> {
         };
         [vertices[0], -vertices[2], vertices[1]]
     }
-}
 
-impl MeshBuilder for BinaryCurveCollectionMeshBuilder<'_> {
-    fn build(&self) -> Mesh {
-        let mut mesh = Mesh::new(PrimitiveTopology::LineStrip, self.render_asset_usages);
-
-        let Ok(number_of_control_points) =
-            usize::try_from(self.bcc.header.number_of_control_points)
-        else {
-            unreachable!("Number of control points exceed usize::MAX.");
+    /// Prepares a 2d chunk by lifting it into the XZ ground plane of a Y-up coordinate
+    /// system.
+    fn y_up_2d(chunk: &[f32]) -> [f32; 3] {
+        let Ok([x, y]): Result<[f32; 2], _> = chunk.try_into() else {
+            unreachable!("Chunk must contain 2 components.");
         };
-        let looping_curves = self.bcc.looping.iter().filter(|s| **s).count();
+        [x, 0., y]
+    }
 
-        let vertices = if self.bcc.header.dimensions == 3 {
-            debug_assert_eq!(self.bcc.control_points.len() % 3, 0);
-            let mapper: fn(&[f32]) -> [f32; 3] = match self.bcc.header.up_direction {
-                1 => Self::y_up,
-                2 => Self::z_up,
-                _ => unreachable!("Invalid up direction."),
-            };
-            let vertices = self
-                .bcc
-                .control_points
-                .chunks(3)
-                .map(mapper)
-                .collect::<Vec<_>>();
-            VertexAttributeValues::Float32x3(vertices)
-        } else {
-            unreachable!("Can only work with 3d curves.");
+    /// Prepares a 2d chunk by lifting it into the XY plane of a Z-up coordinate system.
+    fn z_up_2d(chunk: &[f32]) -> [f32; 3] {
+        let Ok([x, y]): Result<[f32; 2], _> = chunk.try_into() else {
+            unreachable!("Chunk must contain 2 components.");
         };
+        [x, y, 0.]
+    }
+
+    /// Set the line topology used for the produced mesh.
+    pub fn with_topology(mut self, topology: BinaryCurveCollectionMeshTopology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Emit [`PrimitiveTopology::LineList`] instead of the default line strip, for backends
+    /// such as WebGL2 where primitive restart is not reliably available.
+    ///
+    /// Shorthand for [`with_topology`](Self::with_topology) with
+    /// [`BinaryCurveCollectionMeshTopology::LineList`].
+    pub fn line_list(self) -> Self {
+        self.with_topology(BinaryCurveCollectionMeshTopology::LineList)
+    }
+
+    /// Build the index list for the line-strip topology, using `u32::MAX` primitive-restart
+    /// indices between curves.
+    fn line_strip_indices(&self, number_of_control_points: usize) -> Vec<u32> {
+        let looping_curves = self.bcc.looping.iter().filter(|s| **s).count();
         // This will have all control points, +1 for each looping curve to add the first
         // index of the curve at the end of the list, + (number_of_curves - 1) to include
         // primitive restarts
@@ -98,6 +121,81 @@ impl MeshBuilder for BinaryCurveCollectionMeshBuilder<'_> {
         #[cfg(debug_assertions)]
         debug_assert_eq!(indices_capacity, indices.len());
 
+        indices
+    }
+
+    /// Build the index list for the line-list topology, emitting an explicit `(i, i + 1)`
+    /// pair per segment and a closing `(last, first)` pair for looping curves, so no
+    /// primitive-restart index is required.
+    fn line_list_indices(&self) -> Vec<u32> {
+        let mut indices = Vec::new();
+
+        let first_control_points_iter = self.bcc.first_control_points.windows(2);
+        let looping_iter = self.bcc.looping.iter();
+        for (first_control_points, looping) in first_control_points_iter.zip(looping_iter) {
+            let [l, r] = first_control_points else {
+                unreachable!("Window must have 2 values.");
+            };
+
+            let Ok(r) = u32::try_from(*r) else {
+                panic!("Could not fit control points in indices list.");
+            };
+            let Ok(l) = u32::try_from(*l) else {
+                panic!("Could not fit control points in indices list.");
+            };
+
+            for i in l..r.saturating_sub(1) {
+                indices.push(i);
+                indices.push(i + 1);
+            }
+            if *looping && r > l {
+                indices.push(r - 1);
+                indices.push(l);
+            }
+        }
+
+        indices
+    }
+}
+
+impl MeshBuilder for BinaryCurveCollectionMeshBuilder<'_> {
+    fn build(&self) -> Mesh {
+        let topology = match self.topology {
+            BinaryCurveCollectionMeshTopology::LineStrip => PrimitiveTopology::LineStrip,
+            BinaryCurveCollectionMeshTopology::LineList => PrimitiveTopology::LineList,
+        };
+        let mut mesh = Mesh::new(topology, self.render_asset_usages);
+
+        let Ok(number_of_control_points) =
+            usize::try_from(self.bcc.header.number_of_control_points)
+        else {
+            unreachable!("Number of control points exceed usize::MAX.");
+        };
+
+        let dimensions = usize::from(self.bcc.header.dimensions);
+        let mapper: fn(&[f32]) -> [f32; 3] = match (dimensions, self.bcc.header.up_direction) {
+            (3, 1) => Self::y_up,
+            (3, 2) => Self::z_up,
+            (2, 1) => Self::y_up_2d,
+            (2, 2) => Self::z_up_2d,
+            _ => unreachable!("Can only work with 2d or 3d curves."),
+        };
+        debug_assert_eq!(self.bcc.control_points.len() % dimensions, 0);
+        let vertices = VertexAttributeValues::Float32x3(
+            self.bcc
+                .control_points
+                .chunks(dimensions)
+                .map(mapper)
+                .collect::<Vec<_>>(),
+        );
+
+        let indices = match self.topology {
+            BinaryCurveCollectionMeshTopology::LineStrip => {
+                self.line_strip_indices(number_of_control_points)
+            }
+            BinaryCurveCollectionMeshTopology::LineList => self.line_list_indices(),
+        };
+
         mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
         mesh.insert_indices(bevy_mesh::Indices::U32(indices));
 
@@ -111,7 +209,298 @@ impl<'a> Meshable for &'a BinaryCurveCollection {
     fn mesh(&self) -> Self::Output {
         BinaryCurveCollectionMeshBuilder {
             bcc: self,
-            render_asset_usages: Default::default(),
+            render_asset_usages: self.render_asset_usages,
+            topology: BinaryCurveCollectionMeshTopology::default(),
+        }
+    }
+}
+
+/// Builder that sweeps a circular cross-section along each curve to produce a solid tube
+/// [`Mesh`], for rendering cables, hair, or yarn as 3d geometry rather than lines.
+///
+/// The cross-section is oriented with rotation-minimizing (parallel-transport) frames to
+/// avoid the twisting that naive Frenet frames introduce.
+pub struct BinaryCurveCollectionTubeBuilder<'a> {
+    /// The [`BinaryCurveCollection`] from which the tube mesh will be built from
+    bcc: &'a BinaryCurveCollection,
+    /// Render asset usage. Assets with [`RenderAssetUsages::RENDER_WORLD`] will have
+    /// their data moved to the GPU and will become inaccessable from the Cpu.
+    render_asset_usages: RenderAssetUsages,
+    /// Radius of the swept circular cross-section.
+    radius: f32,
+    /// Number of vertices around the cross-section.
+    radial_segments: usize,
+}
+
+impl BinaryCurveCollectionTubeBuilder<'_> {
+    /// Set the radius of the swept circular cross-section.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set the number of vertices placed around the cross-section.
+    pub fn with_radial_segments(mut self, radial_segments: usize) -> Self {
+        self.radial_segments = radial_segments;
+        self
+    }
+
+    /// Map a flattened control point into Bevy's Y-up coordinate system.
+    ///
+    /// 2d points are lifted into the ground/XY plane the same way the line
+    /// [`MeshBuilder`] does, so a tube and the line mesh of the same asset keep the same shape.
+    fn control_point(&self, index: usize) -> Vec3 {
+        let dimensions = usize::from(self.bcc.header.dimensions);
+        let chunk = &self.bcc.control_points[(index * dimensions)..(index * dimensions + dimensions)];
+        match (dimensions, self.bcc.header.up_direction) {
+            (3, 1) => Vec3::new(chunk[0], chunk[1], chunk[2]),
+            (3, 2) => Vec3::new(chunk[0], -chunk[2], chunk[1]),
+            (2, 1) => Vec3::new(chunk[0], 0., chunk[1]),
+            (2, 2) => Vec3::new(chunk[0], chunk[1], 0.),
+            _ => unreachable!("Can only work with 2d or 3d curves."),
+        }
+    }
+}
+
+impl MeshBuilder for BinaryCurveCollectionTubeBuilder<'_> {
+    fn build(&self) -> Mesh {
+        let mut positions: Vec<[f32; 3]> = Vec::new();
+        let mut normals: Vec<[f32; 3]> = Vec::new();
+        let mut uvs: Vec<[f32; 2]> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+
+        let first_control_points_iter = self.bcc.first_control_points.windows(2);
+        let looping_iter = self.bcc.looping.iter();
+        for (first_control_points, looping) in first_control_points_iter.zip(looping_iter) {
+            let [l, r] = first_control_points else {
+                unreachable!("Window must have 2 values.");
+            };
+
+            let centers = (*l..*r).map(|i| self.control_point(i)).collect::<Vec<_>>();
+            // Open curves are left uncapped here; the capped variant lives on the plugin's
+            // `BccTubeMesh`.
+            let Some(swept) = sweep_tube(&centers, *looping, self.radius, self.radial_segments, false)
+            else {
+                continue;
+            };
+
+            let base = u32::try_from(positions.len())
+                .expect("Could not fit control points in indices list.");
+            positions.extend(swept.positions);
+            normals.extend(swept.normals);
+            uvs.extend(swept.uvs);
+            indices.extend(swept.indices.into_iter().map(|i| i + base));
+        }
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, self.render_asset_usages);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.insert_indices(bevy_mesh::Indices::U32(indices));
+
+        mesh
+    }
+}
+
+impl BinaryCurveCollection {
+    /// Create a [`BinaryCurveCollectionTubeBuilder`] that sweeps a circular cross-section
+    /// along each curve.
+    ///
+    /// The radius defaults to `1.0` and the cross-section to `8` radial segments; adjust
+    /// them with [`with_radius`](BinaryCurveCollectionTubeBuilder::with_radius) and
+    /// [`with_radial_segments`](BinaryCurveCollectionTubeBuilder::with_radial_segments).
+    pub fn tube(&self) -> BinaryCurveCollectionTubeBuilder<'_> {
+        BinaryCurveCollectionTubeBuilder {
+            bcc: self,
+            render_asset_usages: self.render_asset_usages,
+            radius: 1.,
+            radial_segments: 8,
+        }
+    }
+}
+
+/// Triangle-list geometry produced by [`sweep_tube`] for a single curve, local to that curve
+/// (indices start at `0`).
+pub(crate) struct SweptTube {
+    /// Ring vertex positions.
+    pub positions: Vec<[f32; 3]>,
+    /// Outward radial normals, one per position.
+    pub normals: Vec<[f32; 3]>,
+    /// `(u around the ring, v along arc length)` coordinates, one per position.
+    pub uvs: Vec<[f32; 2]>,
+    /// Triangle indices into the vertex buffers.
+    pub indices: Vec<u32>,
+}
+
+/// Pick an arbitrary unit vector perpendicular to `tangent`, used to seed the first
+/// rotation-minimizing frame.
+pub(crate) fn seed_reference(tangent: Vec3) -> Vec3 {
+    let axis = if tangent.x.abs() < 0.9 { Vec3::X } else { Vec3::Y };
+    (axis - tangent * axis.dot(tangent)).normalize()
+}
+
+/// Sweep a circular cross-section of `radius` with `radial_segments` vertices along the
+/// polyline `centers`, orienting it with rotation-minimizing (parallel-transport) frames to
+/// avoid the twisting that naive Frenet frames introduce.
+///
+/// Consecutive rings are stitched with two triangles per radial segment; looping curves close
+/// back onto the first ring, and open curves are capped with a flat fan when `cap_ends` is
+/// set. Ring vertices duplicate the seam so the `u` texture coordinate runs `0..=1` cleanly.
+///
+/// This is the single tube-sweep implementation shared by the
+/// [`BinaryCurveCollectionTubeBuilder`] and the `bevy` `BccTubeMesh` system. Returns [`None`]
+/// when there is no segment to sweep or the cross-section has fewer than three segments.
+pub(crate) fn sweep_tube(
+    centers: &[Vec3],
+    looping: bool,
+    radius: f32,
+    radial_segments: usize,
+    cap_ends: bool,
+) -> Option<SweptTube> {
+    // A tube needs at least a segment to sweep and a cross-section to sweep it.
+    if centers.len() < 2 || radial_segments < 3 {
+        return None;
+    }
+    let radial = radial_segments;
+    let ring_stride = radial + 1;
+    let point_count = centers.len();
+
+    // Per-point tangents, wrapping for looping curves.
+    let tangents = (0..point_count)
+        .map(|i| {
+            let next = if looping {
+                centers[(i + 1) % point_count]
+            } else if i + 1 < point_count {
+                centers[i + 1]
+            } else {
+                centers[i]
+            };
+            let prev = if i > 0 {
+                centers[i - 1]
+            } else if looping {
+                centers[point_count - 1]
+            } else {
+                centers[i]
+            };
+            (next - prev).normalize_or_zero()
+        })
+        .collect::<Vec<_>>();
+
+    // Cumulative arc length drives the `v` texture coordinate.
+    let mut arc_lengths = Vec::with_capacity(point_count);
+    let mut length = 0.;
+    for (i, center) in centers.iter().enumerate() {
+        if i > 0 {
+            length += center.distance(centers[i - 1]);
+        }
+        arc_lengths.push(length);
+    }
+    let total_length = length.max(f32::EPSILON);
+
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    // Rotation-minimizing frame, propagated from the first tangent.
+    let mut tangent = tangents[0];
+    let mut u = seed_reference(tangent);
+    let mut v = tangent.cross(u);
+
+    for (ring, &center) in centers.iter().enumerate() {
+        if ring > 0 {
+            // Minimal rotation carrying the previous tangent onto the current one.
+            let next_tangent = tangents[ring];
+            let rotation = Quat::from_rotation_arc(tangent, next_tangent);
+            u = rotation * u;
+            v = rotation * v;
+            tangent = next_tangent;
+        }
+
+        for segment in 0..=radial {
+            let theta = std::f32::consts::TAU * (segment as f32) / (radial as f32);
+            let radial_dir = u * theta.cos() + v * theta.sin();
+            positions.push((center + radial_dir * radius).to_array());
+            normals.push(radial_dir.to_array());
+            uvs.push([segment as f32 / radial as f32, arc_lengths[ring] / total_length]);
+        }
+    }
+
+    // Stitch consecutive rings, and the final ring back to the first when looping.
+    let ring_pairs = if looping { point_count } else { point_count - 1 };
+    for ring in 0..ring_pairs {
+        let this = (ring * ring_stride) as u32;
+        let next = (((ring + 1) % point_count) * ring_stride) as u32;
+        for segment in 0..radial as u32 {
+            let a = this + segment;
+            let b = next + segment;
+            let c = this + segment + 1;
+            let d = next + segment + 1;
+            indices.extend_from_slice(&[a, c, b, c, d, b]);
+        }
+    }
+
+    if cap_ends && !looping {
+        cap_ring(
+            &mut positions,
+            &mut normals,
+            &mut uvs,
+            &mut indices,
+            centers[0],
+            -tangents[0],
+            0,
+            ring_stride,
+            true,
+        );
+        let last_ring = point_count - 1;
+        cap_ring(
+            &mut positions,
+            &mut normals,
+            &mut uvs,
+            &mut indices,
+            centers[last_ring],
+            tangents[last_ring],
+            last_ring * ring_stride,
+            ring_stride,
+            false,
+        );
+    }
+
+    Some(SweptTube {
+        positions,
+        normals,
+        uvs,
+        indices,
+    })
+}
+
+/// Append a flat triangle fan that caps a ring.
+#[allow(clippy::too_many_arguments)]
+fn cap_ring(
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    uvs: &mut Vec<[f32; 2]>,
+    indices: &mut Vec<u32>,
+    center: Vec3,
+    normal: Vec3,
+    ring_start: usize,
+    ring_stride: usize,
+    front: bool,
+) {
+    let center_index = positions.len() as u32;
+    positions.push(center.to_array());
+    normals.push(normal.to_array());
+    uvs.push([0.5, 0.5]);
+
+    let radial = ring_stride - 1;
+    for segment in 0..radial as u32 {
+        let a = (ring_start as u32) + segment;
+        let b = (ring_start as u32) + segment + 1;
+        // Wind the fan so the cap faces along `normal`.
+        if front {
+            indices.extend_from_slice(&[center_index, b, a]);
+        } else {
+            indices.extend_from_slice(&[center_index, a, b]);
         }
     }
 }
@@ -1,26 +1,88 @@
 //! Bevy asset loader for `bcc` files
 
-use bevy_asset::AssetLoader;
+use bevy_asset::{AssetLoader, RenderAssetUsages};
 use bevy_reflect::TypePath;
+use serde::{Deserialize, Serialize};
 
 use crate::{BinaryCurveCollection, BinaryCurveCollectionParserError};
 
+/// Per-load settings for [`BinaryCurveCollectionAssetLoader`], deserialized from the
+/// companion `.bcc.meta` file.
+///
+/// These let a single `.bcc` be loaded in different ways without code changes: rescaling
+/// the control points, overriding the header's up direction, and choosing where the data
+/// lives once a mesh is built from it.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct BinaryCurveCollectionLoaderSettings {
+    /// Uniform factor applied to every control point as it is loaded.
+    pub scale: f32,
+    /// Up direction override that wins over the header byte when set.
+    ///
+    /// * `1`: Y
+    /// * `2`: Z
+    pub up_direction: Option<u8>,
+    /// Render asset usages that meshes built from the loaded collection inherit.
+    ///
+    /// Use [`RenderAssetUsages::RENDER_WORLD`] to upload render data while dropping the
+    /// Cpu-side copy, [`RenderAssetUsages::MAIN_WORLD`] to keep control points around for
+    /// collision, or both to do both.
+    pub render_asset_usages: RenderAssetUsages,
+}
+
+impl Default for BinaryCurveCollectionLoaderSettings {
+    fn default() -> Self {
+        Self {
+            scale: 1.,
+            up_direction: None,
+            render_asset_usages: RenderAssetUsages::default(),
+        }
+    }
+}
+
+impl BinaryCurveCollection {
+    /// Apply [`BinaryCurveCollectionLoaderSettings`] to a freshly parsed collection.
+    ///
+    /// Rejects an `up_direction` override outside `1..=2` just like the parser does, so the
+    /// invariant the mesh and tube builders rely on still holds after the override.
+    fn apply_loader_settings(
+        &mut self,
+        settings: &BinaryCurveCollectionLoaderSettings,
+    ) -> Result<(), BinaryCurveCollectionParserError> {
+        if settings.scale != 1. {
+            for control_point in self.control_points.iter_mut() {
+                *control_point *= settings.scale;
+            }
+        }
+        if let Some(up_direction) = settings.up_direction {
+            if !(1..=2).contains(&up_direction) {
+                return Err(BinaryCurveCollectionParserError::InvalidUpDirection);
+            }
+            self.header.up_direction = up_direction;
+        }
+        self.render_asset_usages = settings.render_asset_usages;
+        Ok(())
+    }
+}
+
 /// Asset loader for [`BinaryCurveCollection`] files
 #[derive(Default, TypePath)]
 pub struct BinaryCurveCollectionAssetLoader;
 
 impl AssetLoader for BinaryCurveCollectionAssetLoader {
     type Asset = BinaryCurveCollection;
-    type Settings = ();
+    type Settings = BinaryCurveCollectionLoaderSettings;
     type Error = BinaryCurveCollectionParserError;
 
     async fn load(
         &self,
         reader: &mut dyn bevy_asset::io::Reader,
-        _settings: &Self::Settings,
+        settings: &Self::Settings,
         _load_context: &mut bevy_asset::LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
-        BinaryCurveCollection::parse_async(reader).await
+        let mut bcc = BinaryCurveCollection::parse_async(reader).await?;
+        bcc.apply_loader_settings(settings)?;
+        Ok(bcc)
     }
 
     fn extensions(&self) -> &[&str] {
@@ -3,12 +3,16 @@
 //! Register the asset loader for [`BinaryCurveCollection`].
 
 mod asset_loader;
+mod tube;
 
-use bevy_app::{App, Plugin};
+use bevy_app::{App, Plugin, Update};
 use bevy_asset::{AssetApp, AssetPlugin};
 use log::error;
 
-use crate::{BinaryCurveCollection, plugin::asset_loader::BinaryCurveCollectionAssetLoader};
+pub use asset_loader::{BinaryCurveCollectionAssetLoader, BinaryCurveCollectionLoaderSettings};
+pub use tube::{BccTubeMesh, BccTubeMeshGenerated};
+
+use crate::BinaryCurveCollection;
 
 /// Bevy plugin for [`BinaryCurveCollection`].
 ///
@@ -27,5 +31,6 @@ impl Plugin for BinaryCurveCollectionPlugin {
 
         app.init_asset::<BinaryCurveCollection>();
         app.init_asset_loader::<BinaryCurveCollectionAssetLoader>();
+        app.add_systems(Update, tube::generate_tube_meshes);
     }
 }
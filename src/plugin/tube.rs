@@ -0,0 +1,158 @@
+//! Tube-mesh generation for loaded [`BinaryCurveCollection`] assets.
+//!
+//! Spawns renderable yarn/cloth geometry by sweeping a circular cross-section along each
+//! sampled curve. Attach a [`BccTubeMesh`] to an entity; once the referenced asset finishes
+//! loading the generation system fills in the meshes.
+
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::prelude::*;
+use bevy_math::Vec3;
+use bevy_mesh::{Indices, Mesh, PrimitiveTopology};
+use bevy_render::mesh::Mesh3d;
+
+use crate::BinaryCurveCollection;
+
+/// Requests tube meshes to be generated from a loaded [`BinaryCurveCollection`].
+///
+/// Spawn this on an entity (together with a material, e.g. `MeshMaterial3d<StandardMaterial>`);
+/// when the asset is available the generation system sweeps a circular cross-section along
+/// each curve and attaches the resulting geometry.
+///
+/// By default (`merge: true`) the geometry is merged into a single [`Mesh`] and attached to
+/// this entity directly, so the material on this entity renders every curve. Set `merge` to
+/// `false` to instead spawn one child entity per curve, each carrying its own `Mesh3d`; in
+/// that mode attach the material to the children yourself, since a bare `Mesh3d` with no
+/// material is not drawn.
+#[derive(Component, Clone)]
+pub struct BccTubeMesh {
+    /// Handle to the curve collection to build the tubes from.
+    pub handle: Handle<BinaryCurveCollection>,
+    /// Radius of the swept cross-section.
+    pub radius: f32,
+    /// Number of segments around the cross-section.
+    pub radial_segments: usize,
+    /// Number of samples taken per curve segment when tessellating.
+    pub curve_subdivisions: usize,
+    /// Merge every curve into a single [`Mesh`] instead of spawning one per curve.
+    pub merge: bool,
+    /// Cap the ends of open curves with a flat fan. Looping curves are always closed.
+    pub cap_ends: bool,
+}
+
+impl Default for BccTubeMesh {
+    fn default() -> Self {
+        Self {
+            handle: Handle::default(),
+            radius: 1.,
+            radial_segments: 8,
+            curve_subdivisions: 8,
+            merge: true,
+            cap_ends: true,
+        }
+    }
+}
+
+/// Marks a [`BccTubeMesh`] whose geometry has already been generated.
+#[derive(Component)]
+pub struct BccTubeMeshGenerated;
+
+/// Generates tube meshes for [`BccTubeMesh`] entities whose assets have finished loading.
+pub fn generate_tube_meshes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    collections: Res<Assets<BinaryCurveCollection>>,
+    tubes: Query<(Entity, &BccTubeMesh), Without<BccTubeMeshGenerated>>,
+) {
+    for (entity, tube) in &tubes {
+        let Some(collection) = collections.get(&tube.handle) else {
+            continue;
+        };
+
+        let curve_meshes = build_tube_meshes(collection, tube);
+        if tube.merge {
+            // Seed the merge from the first curve so `Mesh::merge` has attributes to extend;
+            // merging into an attribute-less mesh would drop every vertex.
+            let mut curve_meshes = curve_meshes.into_iter();
+            let Some(mut merged) = curve_meshes.next() else {
+                commands.entity(entity).insert(BccTubeMeshGenerated);
+                continue;
+            };
+            for mesh in curve_meshes {
+                merged.merge(&mesh).ok();
+            }
+            commands
+                .entity(entity)
+                .insert((Mesh3d(meshes.add(merged)), BccTubeMeshGenerated));
+        } else {
+            commands.entity(entity).insert(BccTubeMeshGenerated);
+            commands.entity(entity).with_children(|parent| {
+                for mesh in curve_meshes {
+                    parent.spawn(Mesh3d(meshes.add(mesh)));
+                }
+            });
+        }
+    }
+}
+
+/// Build one tube [`Mesh`] per curve in the collection.
+fn build_tube_meshes(collection: &BinaryCurveCollection, tube: &BccTubeMesh) -> Vec<Mesh> {
+    let usages = collection.render_asset_usages();
+    (0..collection.header().number_of_curves() as usize)
+        .filter_map(|n| {
+            let centers = collection
+                .tessellate_curve(n, tube.curve_subdivisions)
+                .into_iter()
+                .map(|point| {
+                    map_up(
+                        collection.header().dimensions(),
+                        collection.header().up_direction(),
+                        point,
+                    )
+                })
+                .collect::<Vec<_>>();
+            let looping = collection.looping(n).unwrap_or(false);
+            build_tube(&centers, looping, tube, usages)
+        })
+        .collect()
+}
+
+/// Sweep a tube along `centers`, returning [`None`] when there is nothing to build.
+///
+/// The geometry comes from the shared [`sweep_tube`](crate::mesh::sweep_tube) used by the
+/// [`BinaryCurveCollectionTubeBuilder`](crate::mesh::BinaryCurveCollectionTubeBuilder) so the
+/// two stay in lock-step; this only wraps it into a [`Mesh`] with the collection's usages.
+fn build_tube(
+    centers: &[Vec3],
+    looping: bool,
+    tube: &BccTubeMesh,
+    usages: bevy_asset::RenderAssetUsages,
+) -> Option<Mesh> {
+    let swept = crate::mesh::sweep_tube(
+        centers,
+        looping,
+        tube.radius,
+        tube.radial_segments,
+        tube.cap_ends,
+    )?;
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, usages);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, swept.positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, swept.normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, swept.uvs);
+    mesh.insert_indices(Indices::U32(swept.indices));
+    Some(mesh)
+}
+
+/// Map a sampled point from the collection's coordinate system into Bevy's Y-up space.
+///
+/// 2d points (whose third channel `tessellate_curve` leaves at zero) are lifted into the
+/// ground/XY plane exactly as the line and tube [`MeshBuilder`](crate::mesh)s do, so the tube
+/// mesh and the line mesh of the same asset render as the same shape.
+fn map_up(dimensions: u8, up_direction: u8, point: [f32; 3]) -> Vec3 {
+    match (dimensions, up_direction) {
+        (2, 1) => Vec3::new(point[0], 0., point[1]),
+        (2, 2) => Vec3::new(point[0], point[1], 0.),
+        (_, 2) => Vec3::new(point[0], -point[2], point[1]),
+        _ => Vec3::new(point[0], point[1], point[2]),
+    }
+}
@@ -22,9 +22,11 @@ impl BinaryCurveCollection {
 
         let mut precision = 0;
         reader.read_exact(std::slice::from_mut(&mut precision))?;
-        if precision != 0x44 {
-            return Err(BinaryCurveCollectionParserError::InvalidSignature);
-        }
+        let component_width = match precision {
+            0x44 => 4,
+            0x48 => 8,
+            _ => return Err(BinaryCurveCollectionParserError::InvalidPrecision),
+        };
 
         let mut curve = [0; 2];
         reader.read_exact(&mut curve)?;
@@ -34,6 +36,9 @@ impl BinaryCurveCollection {
 
         let mut dimensions = 0;
         reader.read_exact(std::slice::from_mut(&mut dimensions))?;
+        if !(2..=3).contains(&dimensions) {
+            return Err(BinaryCurveCollectionParserError::InvalidDimensions);
+        }
 
         let mut up_direction = 0;
         reader.read_exact(std::slice::from_mut(&mut up_direction))?;
@@ -61,10 +66,13 @@ impl BinaryCurveCollection {
 
         let mut looping = vec![false; size_of_curves].into_boxed_slice();
         let mut first_control_points = vec![0; size_of_curves + 1].into_boxed_slice();
-        let mut control_points = vec![0.; size_of_control_points * 3].into_boxed_slice();
+        let mut control_points =
+            vec![0.; size_of_control_points * usize::from(dimensions)].into_boxed_slice();
 
         Self::read_curves(
             &mut reader,
+            usize::from(dimensions),
+            usize::from(component_width),
             &mut looping,
             &mut first_control_points,
             &mut control_points,
@@ -74,6 +82,7 @@ impl BinaryCurveCollection {
             header: BinaryCurveCollectionHeader {
                 signature,
                 precision,
+                component_width,
                 curve,
                 dimensions,
                 up_direction,
@@ -84,6 +93,8 @@ impl BinaryCurveCollection {
             looping,
             first_control_points,
             control_points,
+            #[cfg(feature = "bevy")]
+            render_asset_usages: bevy_asset::RenderAssetUsages::default(),
         })
     }
 
@@ -104,9 +115,11 @@ impl BinaryCurveCollection {
         reader
             .read_exact(std::slice::from_mut(&mut precision))
             .await?;
-        if precision != 0x44 {
-            return Err(BinaryCurveCollectionParserError::InvalidSignature);
-        }
+        let component_width = match precision {
+            0x44 => 4,
+            0x48 => 8,
+            _ => return Err(BinaryCurveCollectionParserError::InvalidPrecision),
+        };
 
         let mut curve = [0; 2];
         reader.read_exact(&mut curve).await?;
@@ -118,6 +131,9 @@ impl BinaryCurveCollection {
         reader
             .read_exact(std::slice::from_mut(&mut dimensions))
             .await?;
+        if !(2..=3).contains(&dimensions) {
+            return Err(BinaryCurveCollectionParserError::InvalidDimensions);
+        }
 
         let mut up_direction = 0;
         reader
@@ -147,10 +163,13 @@ impl BinaryCurveCollection {
 
         let mut looping = vec![false; size_of_curves].into_boxed_slice();
         let mut first_control_points = vec![0; size_of_curves + 1].into_boxed_slice();
-        let mut control_points = vec![0.; size_of_control_points * 3].into_boxed_slice();
+        let mut control_points =
+            vec![0.; size_of_control_points * usize::from(dimensions)].into_boxed_slice();
 
         Self::read_curves_async(
             &mut reader,
+            usize::from(dimensions),
+            usize::from(component_width),
             &mut looping,
             &mut first_control_points,
             &mut control_points,
@@ -161,6 +180,7 @@ impl BinaryCurveCollection {
             header: BinaryCurveCollectionHeader {
                 signature,
                 precision,
+                component_width,
                 curve,
                 dimensions,
                 up_direction,
@@ -171,12 +191,16 @@ impl BinaryCurveCollection {
             looping,
             first_control_points,
             control_points,
+            #[cfg(feature = "bevy")]
+            render_asset_usages: bevy_asset::RenderAssetUsages::default(),
         })
     }
 
     /// Read the curves and control points of those curves
     fn read_curves<T: Read>(
         reader: &mut T,
+        dimensions: usize,
+        component_width: usize,
         looping: &mut [bool],
         first_control_points: &mut [usize],
         mut control_points: &mut [f32],
@@ -197,18 +221,27 @@ impl BinaryCurveCollection {
             *looping = curve_control_points < 0;
             *first_control_point = previous_control_point_start;
 
-            let Ok(size) = usize::try_from(curve_control_points.abs()) else {
+            let Ok(size) = usize::try_from(curve_control_points.unsigned_abs()) else {
                 return Err(BinaryCurveCollectionParserError::TooManyControlPoints);
             };
             previous_control_point_start += size;
 
-            reader.read_exact(unsafe {
-                std::slice::from_raw_parts_mut(
-                    control_points[..(size * 3)].as_mut_ptr() as *mut u8,
-                    size * 4 * 3,
-                )
-            })?;
-            control_points = &mut control_points[(size * 3)..];
+            let components = size * dimensions;
+            let (target, rest) = control_points.split_at_mut(components);
+            match component_width {
+                4 => reader.read_exact(unsafe {
+                    std::slice::from_raw_parts_mut(target.as_mut_ptr() as *mut u8, components * 4)
+                })?,
+                8 => {
+                    for component in target.iter_mut() {
+                        let mut bytes = [0; 8];
+                        reader.read_exact(&mut bytes)?;
+                        *component = f64::from_le_bytes(bytes) as f32;
+                    }
+                }
+                _ => unreachable!("Component width validated during header parsing."),
+            }
+            control_points = rest;
         }
         first_control_points[first_control_points.len() - 1] = previous_control_point_start;
 
@@ -220,6 +253,8 @@ impl BinaryCurveCollection {
     /// Read the curves and control points of those curves in `async` context
     async fn read_curves_async<T: AsyncRead + Unpin>(
         reader: &mut T,
+        dimensions: usize,
+        component_width: usize,
         looping: &mut [bool],
         first_control_points: &mut [usize],
         mut control_points: &mut [f32],
@@ -240,20 +275,34 @@ impl BinaryCurveCollection {
             *looping = curve_control_points < 0;
             *first_control_point = previous_control_point_start;
 
-            let Ok(size) = usize::try_from(curve_control_points.abs()) else {
+            let Ok(size) = usize::try_from(curve_control_points.unsigned_abs()) else {
                 return Err(BinaryCurveCollectionParserError::TooManyControlPoints);
             };
             previous_control_point_start += size;
 
-            reader
-                .read_exact(unsafe {
-                    std::slice::from_raw_parts_mut(
-                        control_points[..(size * 3)].as_mut_ptr() as *mut u8,
-                        size * 4 * 3,
-                    )
-                })
-                .await?;
-            control_points = &mut control_points[(size * 3)..];
+            let components = size * dimensions;
+            let (target, rest) = control_points.split_at_mut(components);
+            match component_width {
+                4 => {
+                    reader
+                        .read_exact(unsafe {
+                            std::slice::from_raw_parts_mut(
+                                target.as_mut_ptr() as *mut u8,
+                                components * 4,
+                            )
+                        })
+                        .await?
+                }
+                8 => {
+                    for component in target.iter_mut() {
+                        let mut bytes = [0; 8];
+                        reader.read_exact(&mut bytes).await?;
+                        *component = f64::from_le_bytes(bytes) as f32;
+                    }
+                }
+                _ => unreachable!("Component width validated during header parsing."),
+            }
+            control_points = rest;
         }
         first_control_points[first_control_points.len() - 1] = previous_control_point_start;
 
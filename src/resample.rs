@@ -0,0 +1,199 @@
+//! Catmull-Rom resampling of a [`BinaryCurveCollection`].
+
+use crate::BinaryCurveCollection;
+
+/// Smallest knot spacing used to keep the centripetal parameterization well defined when
+/// consecutive control points coincide.
+const KNOT_EPSILON: f32 = f32::EPSILON;
+
+impl BinaryCurveCollection {
+    /// Resample every curve as a centripetal Catmull-Rom spline, subdividing each segment
+    /// into `subdivisions` samples.
+    ///
+    /// The control points stored in a [`BinaryCurveCollection`] are raw polylines, so coarse
+    /// curves render as visibly faceted segments. This reinterprets them as a centripetal
+    /// (`α = 0.5`) Catmull-Rom spline and returns a new collection with denser control points
+    /// that feeds directly into the [`mesh`](crate::mesh) and tube builders, without touching
+    /// the source file.
+    ///
+    /// Endpoints of open curves are clamped by duplicating the first and last control point;
+    /// looping curves wrap around so the spline closes. `subdivisions` is clamped to at least
+    /// `1`.
+    ///
+    /// Note that this differs from [`sample_curve`](Self::sample_curve) /
+    /// [`tessellate_curve`](Self::tessellate_curve), which reflect the phantom endpoints
+    /// (`P₋₁ = 2*P₀ − P₁`) rather than clamping them, so the two APIs bow slightly differently
+    /// near open-curve endpoints. Clamping keeps the resampled curve strictly inside the
+    /// control polygon's endpoints, which is what a denser drop-in collection wants; reach for
+    /// the evaluation API when you need the reflected, tangent-preserving endpoints instead.
+    /// This method is also *centripetal* (`α = 0.5`), whereas the evaluation API uses the
+    /// uniform parameterization the header guarantees.
+    pub fn resample(&self, subdivisions: usize) -> BinaryCurveCollection {
+        let dimensions = usize::from(self.header.dimensions);
+        let k = subdivisions.max(1);
+
+        let mut looping = Vec::with_capacity(self.looping.len());
+        let mut first_control_points = Vec::with_capacity(self.first_control_points.len());
+        let mut control_points = Vec::new();
+
+        let mut start = 0;
+        for (window, is_looping) in self.first_control_points.windows(2).zip(self.looping.iter()) {
+            let [l, r] = window else {
+                unreachable!("Window must have 2 values.");
+            };
+            let point_count = r - l;
+            let points = &self.control_points[(l * dimensions)..(r * dimensions)];
+
+            looping.push(*is_looping);
+            first_control_points.push(start);
+
+            // Index into `points` with endpoint clamping (open) or wrap-around (looping).
+            let point = |index: isize| -> &[f32] {
+                let count = point_count as isize;
+                let index = if *is_looping {
+                    index.rem_euclid(count)
+                } else {
+                    index.clamp(0, count - 1)
+                } as usize;
+                &points[(index * dimensions)..(index * dimensions + dimensions)]
+            };
+
+            let segment_count = if *is_looping {
+                point_count
+            } else {
+                point_count.saturating_sub(1)
+            };
+
+            for segment in 0..segment_count {
+                let segment = segment as isize;
+                let p0 = point(segment - 1);
+                let p1 = point(segment);
+                let p2 = point(segment + 1);
+                let p3 = point(segment + 2);
+
+                let t0 = 0.;
+                let t1 = t0 + distance(p0, p1).sqrt().max(KNOT_EPSILON);
+                let t2 = t1 + distance(p1, p2).sqrt().max(KNOT_EPSILON);
+                let t3 = t2 + distance(p2, p3).sqrt().max(KNOT_EPSILON);
+
+                for sample in 0..k {
+                    let t = t1 + (t2 - t1) * (sample as f32 / k as f32);
+                    for component in 0..dimensions {
+                        control_points.push(catmull_rom(
+                            p0[component],
+                            p1[component],
+                            p2[component],
+                            p3[component],
+                            t0,
+                            t1,
+                            t2,
+                            t3,
+                            t,
+                        ));
+                    }
+                }
+            }
+
+            // Open curves stop at the final control point, which no segment emits.
+            if !*is_looping && point_count > 0 {
+                control_points.extend_from_slice(point(point_count as isize - 1));
+            }
+
+            start = control_points.len() / dimensions;
+        }
+        first_control_points.push(start);
+
+        let mut header = self.header;
+        header.number_of_control_points = start as u64;
+
+        BinaryCurveCollection {
+            header,
+            looping: looping.into_boxed_slice(),
+            first_control_points: first_control_points.into_boxed_slice(),
+            control_points: control_points.into_boxed_slice(),
+            #[cfg(feature = "bevy")]
+            render_asset_usages: self.render_asset_usages,
+        }
+    }
+}
+
+/// Euclidean distance between two control points.
+fn distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| (a - b).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// Evaluate a single component of a Catmull-Rom segment using the non-uniform (Barry-Goldman)
+/// blend, so the centripetal knot spacing is honored.
+#[allow(clippy::too_many_arguments)]
+fn catmull_rom(
+    p0: f32,
+    p1: f32,
+    p2: f32,
+    p3: f32,
+    t0: f32,
+    t1: f32,
+    t2: f32,
+    t3: f32,
+    t: f32,
+) -> f32 {
+    let a1 = (t1 - t) / (t1 - t0) * p0 + (t - t0) / (t1 - t0) * p1;
+    let a2 = (t2 - t) / (t2 - t1) * p1 + (t - t1) / (t2 - t1) * p2;
+    let a3 = (t3 - t) / (t3 - t2) * p2 + (t - t2) / (t3 - t2) * p3;
+    let b1 = (t2 - t) / (t2 - t0) * a1 + (t - t0) / (t2 - t0) * a2;
+    let b2 = (t3 - t) / (t3 - t1) * a2 + (t - t1) / (t3 - t1) * a3;
+    (t2 - t) / (t2 - t1) * b1 + (t - t1) / (t2 - t1) * b2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::BinaryCurveCollectionBuilder;
+
+    #[test]
+    fn resample_straight_line_stays_collinear() {
+        let bcc = BinaryCurveCollectionBuilder::new(3, 1)
+            .add_curve(false, vec![0., 0., 0., 1., 0., 0., 2., 0., 0.])
+            .build();
+        let dense = bcc.resample(4);
+
+        // Two segments subdivided into four samples each, plus the final control point.
+        assert_eq!(dense.header().number_of_control_points, 9);
+        let points = dense.control_points();
+        assert_eq!(points.len(), 9 * 3);
+
+        // Endpoints are preserved and the line stays on the x axis, monotonically increasing.
+        assert!((points[0] - 0.).abs() < 1e-5);
+        assert!((points[points.len() - 3] - 2.).abs() < 1e-5);
+        for chunk in points.chunks(3) {
+            assert!(chunk[1].abs() < 1e-5);
+            assert!(chunk[2].abs() < 1e-5);
+        }
+        for window in points.chunks(3).collect::<Vec<_>>().windows(2) {
+            assert!(window[1][0] >= window[0][0] - 1e-5);
+        }
+    }
+
+    #[test]
+    fn resample_looping_curve_count() {
+        let bcc = BinaryCurveCollectionBuilder::new(3, 1)
+            .add_curve(true, vec![0., 0., 0., 1., 0., 0., 1., 1., 0., 0., 1., 0.])
+            .build();
+        // Looping: four segments subdivided twice, with no appended endpoint.
+        let dense = bcc.resample(2);
+        assert_eq!(dense.header().number_of_control_points, 8);
+        assert_eq!(dense.looping(0), Some(true));
+    }
+
+    #[test]
+    fn resample_clamps_subdivisions_to_one() {
+        let bcc = BinaryCurveCollectionBuilder::new(3, 1)
+            .add_curve(false, vec![0., 0., 0., 1., 0., 0.])
+            .build();
+        // `0` is clamped to `1`: a single segment emits one sample plus the final point.
+        let dense = bcc.resample(0);
+        assert_eq!(dense.header().number_of_control_points, 2);
+    }
+}
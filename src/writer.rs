@@ -0,0 +1,190 @@
+//! Writer for a [`BinaryCurveCollection`].
+
+use std::io::Write;
+
+use futures_io::AsyncWrite;
+use futures_util::AsyncWriteExt;
+use log::{debug, trace};
+
+use crate::BinaryCurveCollection;
+
+/// Errors that can happen while writing a [`BinaryCurveCollection`].
+#[derive(Debug)]
+pub enum BinaryCurveCollectionWriterError {
+    /// The number of curves does not match the looping and first-control-point tables.
+    CurveCountMismatch,
+    /// The number of control points does not match the first-control-point table or the
+    /// flattened control-point buffer.
+    ControlPointCountMismatch,
+    /// An Io error occurred while writing to the [`std::io::Write`].
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for BinaryCurveCollectionWriterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CurveCountMismatch => write!(
+                f,
+                "Number of curves does not match the collection's curve tables."
+            ),
+            Self::ControlPointCountMismatch => write!(
+                f,
+                "Number of control points does not match the collection's control point tables."
+            ),
+            Self::Io(err) => write!(f, "Io error during writing of BCC file. {err}."),
+        }
+    }
+}
+
+impl std::error::Error for BinaryCurveCollectionWriterError {}
+
+impl From<std::io::Error> for BinaryCurveCollectionWriterError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl BinaryCurveCollection {
+    /// Writes this [`BinaryCurveCollection`] into a [`Write`] as a `.bcc` byte stream.
+    ///
+    /// The output round-trips through [`BinaryCurveCollection::parse`]. This method has an
+    /// `async` counterpart [`BinaryCurveCollection::write_async`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_mod_bcc::BinaryCurveCollection;
+    /// # const binary_curve_collection: &[u8] = &[
+    /// #   b'B', b'C', b'C', 0x44, b'C', b'0', 3, 2, 1, 0, 0, 0, 0, 0, 0, 0,
+    /// #   1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    /// #   0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    /// #   0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    /// #   1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x80, 0x3f, 0, 0, 0, 0x40
+    /// # ];
+    /// let bcc = BinaryCurveCollection::parse(binary_curve_collection).unwrap();
+    /// let mut bytes = Vec::new();
+    /// bcc.write(&mut bytes).unwrap();
+    /// assert_eq!(bytes, binary_curve_collection);
+    /// ```
+    pub fn write<W: Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), BinaryCurveCollectionWriterError> {
+        debug!("Writing BinaryCurveCollection");
+        self.validate()?;
+        writer.write_all(&self.header.signature)?;
+        writer.write_all(std::slice::from_ref(&self.header.precision))?;
+        writer.write_all(&self.header.curve)?;
+        writer.write_all(std::slice::from_ref(&self.header.dimensions))?;
+        writer.write_all(std::slice::from_ref(&self.header.up_direction))?;
+        writer.write_all(&self.header.number_of_curves.to_le_bytes())?;
+        writer.write_all(&self.header.number_of_control_points.to_le_bytes())?;
+        writer.write_all(&self.header.file_information)?;
+
+        let dimensions = usize::from(self.header.dimensions);
+        let first_control_points_iter = self.first_control_points.windows(2);
+        for (first_control_points, looping) in first_control_points_iter.zip(self.looping.iter()) {
+            let [l, r] = first_control_points else {
+                unreachable!("Window must have 2 values.");
+            };
+            trace!("Writing a curve with {} control points.", r - l);
+
+            let count = (r - l) as i32;
+            let count = if *looping { -count } else { count };
+            writer.write_all(&count.to_le_bytes())?;
+
+            for control_point in &self.control_points[(l * dimensions)..(r * dimensions)] {
+                match self.header.component_width {
+                    8 => writer.write_all(&f64::from(*control_point).to_le_bytes())?,
+                    _ => writer.write_all(&control_point.to_le_bytes())?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes this [`BinaryCurveCollection`] into an [`AsyncWrite`] as a `.bcc` byte stream.
+    ///
+    /// This method has a `sync` counterpart [`BinaryCurveCollection::write`].
+    pub async fn write_async<W: AsyncWrite + Unpin>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), BinaryCurveCollectionWriterError> {
+        debug!("Writing BinaryCurveCollection");
+        self.validate()?;
+        writer.write_all(&self.header.signature).await?;
+        writer
+            .write_all(std::slice::from_ref(&self.header.precision))
+            .await?;
+        writer.write_all(&self.header.curve).await?;
+        writer
+            .write_all(std::slice::from_ref(&self.header.dimensions))
+            .await?;
+        writer
+            .write_all(std::slice::from_ref(&self.header.up_direction))
+            .await?;
+        writer
+            .write_all(&self.header.number_of_curves.to_le_bytes())
+            .await?;
+        writer
+            .write_all(&self.header.number_of_control_points.to_le_bytes())
+            .await?;
+        writer.write_all(&self.header.file_information).await?;
+
+        let dimensions = usize::from(self.header.dimensions);
+        let first_control_points_iter = self.first_control_points.windows(2);
+        for (first_control_points, looping) in first_control_points_iter.zip(self.looping.iter()) {
+            let [l, r] = first_control_points else {
+                unreachable!("Window must have 2 values.");
+            };
+            trace!("Writing a curve with {} control points.", r - l);
+
+            let count = (r - l) as i32;
+            let count = if *looping { -count } else { count };
+            writer.write_all(&count.to_le_bytes()).await?;
+
+            for control_point in &self.control_points[(l * dimensions)..(r * dimensions)] {
+                match self.header.component_width {
+                    8 => {
+                        writer
+                            .write_all(&f64::from(*control_point).to_le_bytes())
+                            .await?
+                    }
+                    _ => writer.write_all(&control_point.to_le_bytes()).await?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encodes this [`BinaryCurveCollection`] into an owned `.bcc` byte buffer.
+    ///
+    /// Convenience wrapper over [`BinaryCurveCollection::write`].
+    pub fn to_bytes(&self) -> Result<Vec<u8>, BinaryCurveCollectionWriterError> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Checks that the header counts agree with the curve and control-point tables before
+    /// they are committed to the stream.
+    fn validate(&self) -> Result<(), BinaryCurveCollectionWriterError> {
+        let number_of_curves = self.looping.len();
+        if u64::try_from(number_of_curves) != Ok(self.header.number_of_curves)
+            || self.first_control_points.len() != number_of_curves + 1
+        {
+            return Err(BinaryCurveCollectionWriterError::CurveCountMismatch);
+        }
+
+        let dimensions = usize::from(self.header.dimensions);
+        let number_of_control_points = self.first_control_points.last().copied().unwrap_or(0);
+        if u64::try_from(number_of_control_points) != Ok(self.header.number_of_control_points)
+            || self.control_points.len() != number_of_control_points * dimensions
+        {
+            return Err(BinaryCurveCollectionWriterError::ControlPointCountMismatch);
+        }
+
+        Ok(())
+    }
+}